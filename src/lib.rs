@@ -4,8 +4,14 @@
 
 #![cfg_attr(feature = "read_initializer", feature(read_initializer))]
 
+use std::borrow::Borrow;
 use std::cmp;
-use std::io::{Error,ErrorKind,Read, Seek, SeekFrom, Result};
+use std::fs::File;
+use std::io::{BufRead, Error,ErrorKind,Read, Seek, SeekFrom, Result, Write};
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+#[cfg(windows)]
+use std::os::windows::fs::FileExt;
 
 /// Extension trait for `Read + Seek` to support `takes`
 pub trait Ext : Read + Seek + Sized {
@@ -14,11 +20,31 @@ pub trait Ext : Read + Seek + Sized {
     /// # Errors
     /// Returns an error if the current offset could not be seeked.
     fn takes(self, limit: u64) -> Result<Takes<Self>>;
+
+    /// Returns a seekable, zero-based logical window over `begin..(begin + length)` of `self`.
+    ///
+    /// Unlike [`takes`](Ext::takes), positions reported and accepted by the returned [`Slice`]
+    /// are relative to `begin`, so `SeekFrom::Start(0)` always refers to the start of the slice
+    /// instead of the underlying reader.
+    ///
+    /// # Errors
+    /// Returns an error if the reader could not be seeked to `begin`.
+    fn slice(self, begin: u64, length: u64) -> Result<Slice<Self>>;
+
+    /// Returns a seekable Take that also forwards every freshly read byte to `writer`.
+    ///
+    /// Seeking never re-emits bytes already written; a forward seek instead reads through the
+    /// skipped gap (writing it) before repositioning, so `writer` ends up receiving exactly the
+    /// window covered by reads and seeks, with no gaps and no duplicates.
+    ///
+    /// # Errors
+    /// Returns an error if the current offset could not be seeked.
+    fn tee<W: Write>(self, limit: u64, writer: W) -> Result<Tee<Self, W>>;
 }
 
 impl<R: Read + Seek> Ext for R {
     fn takes(mut self, limit: u64) -> Result<Takes<Self>> {
-        let start = self.seek(SeekFrom::Current(0))?;
+        let start = self.stream_position()?;
 
         Ok(Takes {
             inner: self,
@@ -27,6 +53,30 @@ impl<R: Read + Seek> Ext for R {
             current: 0,
         })
     }
+
+    fn slice(mut self, begin: u64, length: u64) -> Result<Slice<Self>> {
+        self.seek(SeekFrom::Start(begin))?;
+
+        Ok(Slice {
+            inner: self,
+            begin,
+            length,
+            pos: 0,
+        })
+    }
+
+    fn tee<W: Write>(mut self, limit: u64, writer: W) -> Result<Tee<Self, W>> {
+        let start = self.stream_position()?;
+
+        Ok(Tee {
+            inner: self,
+            writer,
+            start,
+            limit,
+            current: 0,
+            max: 0,
+        })
+    }
 }
 
 /// A Seekable `Take` implementation.
@@ -60,26 +110,633 @@ impl<R: Read> Read for Takes<R> {
     }
 }
 
+impl<R: BufRead> BufRead for Takes<R> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        let rem = self.limit - self.current;
+        // Don't call into inner reader at all at EOF because it may still block
+        if rem == 0 {
+            return Ok(&[]);
+        }
+
+        let buf = self.inner.fill_buf()?;
+        let max = cmp::min(buf.len() as u64, rem) as usize;
+        Ok(&buf[..max])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.current += amt as u64;
+    }
+}
+
+#[cfg(test)]
+mod buf_read_tests {
+    use std::io::{BufRead, Cursor};
+    use crate::Ext;
+
+    #[test]
+    fn read_until_stops_at_the_window_boundary() {
+        let mut takes = Cursor::new(b"aaa\nbbb\nccc\n".to_vec()).takes(8).unwrap();
+
+        let mut line = Vec::new();
+        takes.read_until(b'\n', &mut line).unwrap();
+        assert_eq!(line, b"aaa\n");
+
+        line.clear();
+        takes.read_until(b'\n', &mut line).unwrap();
+        assert_eq!(line, b"bbb\n");
+
+        // The window only covers "aaa\nbbb\n" (8 bytes), so this hits the logical EOF even
+        // though the underlying reader still has more data.
+        line.clear();
+        takes.read_until(b'\n', &mut line).unwrap();
+        assert!(line.is_empty());
+    }
+}
+
 /// The absolute offsets used in the Seek implementation are *identical* to those in the underlying
 /// Read.
 /// In other words, `SeekFrom::Start(0)` may seek beyond range and cause error.
 impl<R: Seek> Seek for Takes<R> {
     fn seek(&mut self, seek: SeekFrom) -> Result<u64> {
-        Ok(match seek {
-            SeekFrom::Start(offset) => {
-                if offset < self.start || offset > self.current {
-                    return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond Takes range"));
-                }
-                self.inner.seek(SeekFrom::Start(offset))?
-            },
-            SeekFrom::Current(delta) => {
-                let dest = (self.current as i64) + delta;
-                if dest < 0 || (dest as u64) > self.limit {
-                    return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond Takes range"));
+        let end = self.start + self.limit;
+
+        let target = match seek {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(delta) => (self.start + self.current) as i64 + delta,
+            SeekFrom::End(delta) => end as i64 + delta,
+        };
+
+        if target < self.start as i64 || (target as u64) > end {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond Takes range"));
+        }
+
+        let target = target as u64;
+        self.inner.seek(SeekFrom::Start(target))?;
+        self.current = target - self.start;
+        Ok(target)
+    }
+}
+
+impl<R> Takes<R> {
+    /// Returns the current logical position within the window, i.e. the offset of the current
+    /// pointer from `start`.
+    pub fn position(&self) -> u64 {
+        self.current
+    }
+
+    /// Returns the total size of the window, i.e. the value of `limit` passed to [`Ext::takes`].
+    pub fn size(&self) -> u64 {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod takes_tests {
+    use std::io::{Cursor, Read, Seek, SeekFrom};
+    use crate::Ext;
+
+    #[test]
+    fn seek_end_lands_inside_the_window() {
+        let mut takes = Cursor::new(b"0123456789".to_vec()).takes(5).unwrap();
+        assert_eq!(takes.seek(SeekFrom::End(-2)).unwrap(), 3);
+        let mut buf = [0u8; 2];
+        takes.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"34");
+    }
+
+    #[test]
+    fn seek_end_beyond_the_window_errors() {
+        let mut takes = Cursor::new(b"0123456789".to_vec()).takes(5).unwrap();
+        assert!(takes.seek(SeekFrom::End(1)).is_err());
+    }
+
+    #[test]
+    fn forward_seek_into_unread_bytes_is_allowed() {
+        let mut takes = Cursor::new(b"0123456789".to_vec()).takes(5).unwrap();
+        assert_eq!(takes.seek(SeekFrom::Start(4)).unwrap(), 4);
+        let mut buf = [0u8; 1];
+        takes.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"4");
+    }
+}
+
+/// A zero-based logical window over a `Read + Seek`, created by [`Ext::slice`].
+///
+/// Unlike [`Takes`], positions are always relative to `begin`: position `0` is the start of the
+/// slice and position `length` is its end, regardless of where `begin` lies in the underlying
+/// reader.
+pub struct Slice<R> {
+    inner: R,
+    begin: u64,
+    length: u64,
+    pos: u64, // number of bytes of current pointer from begin
+}
+
+impl<R: Read> Read for Slice<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let rem = self.length - self.pos;
+        // Don't call into inner reader at all at EOF because it may still block
+        if rem == 0 {
+            return Ok(0);
+        }
+
+        let max = cmp::min(buf.len() as u64, rem) as usize;
+        let n = self.inner.read(&mut buf[..max])?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+
+    #[cfg(feature = "read_initializer")]
+    unsafe fn initializer(&self) -> Initializer {
+        self.inner.initializer()
+    }
+}
+
+impl<R: Seek> Seek for Slice<R> {
+    fn seek(&mut self, seek: SeekFrom) -> Result<u64> {
+        let target = match seek {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(delta) => self.pos as i64 + delta,
+            SeekFrom::End(delta) => self.length as i64 + delta,
+        };
+
+        if target < 0 || (target as u64) > self.length {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond Slice range"));
+        }
+
+        let target = target as u64;
+        self.inner.seek(SeekFrom::Start(self.begin + target))?;
+        self.pos = target;
+        Ok(self.pos)
+    }
+}
+
+impl<R> Slice<R> {
+    /// Returns the current logical position within the slice, relative to `begin`.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Returns the total size of the slice, i.e. the `length` passed to [`Ext::slice`].
+    pub fn size(&self) -> u64 {
+        self.length
+    }
+}
+
+#[cfg(test)]
+mod slice_tests {
+    use std::io::{Cursor, Read, Seek, SeekFrom};
+    use crate::Ext;
+
+    #[test]
+    fn position_zero_is_relative_to_begin() {
+        let mut slice = Cursor::new(b"0123456789".to_vec()).slice(4, 3).unwrap();
+        let mut buf = Vec::new();
+        slice.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"456");
+    }
+
+    #[test]
+    fn seek_start_zero_does_not_hit_the_underlying_offset() {
+        let mut slice = Cursor::new(b"0123456789".to_vec()).slice(4, 3).unwrap();
+        assert_eq!(slice.seek(SeekFrom::Start(0)).unwrap(), 0);
+        let mut buf = [0u8; 1];
+        slice.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"4");
+    }
+
+    #[test]
+    fn seek_end_is_anchored_at_length() {
+        let mut slice = Cursor::new(b"0123456789".to_vec()).slice(4, 3).unwrap();
+        assert_eq!(slice.seek(SeekFrom::End(-1)).unwrap(), 2);
+        let mut buf = [0u8; 1];
+        slice.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"6");
+    }
+}
+
+/// A positioned read that does not move any shared file cursor.
+///
+/// This allows multiple independent windows (e.g. several [`PosTakes`]) to share one file
+/// handle without contending over the single OS-level file position that plain `Read + Seek`
+/// would require.
+///
+/// # Platform note
+/// On Unix this is backed by `read_at` (`pread`), which genuinely never touches the file's
+/// position. On Windows it is backed by `FileExt::seek_read`, which *does* move the shared
+/// file pointer internally even though it restores the observable read offset semantics —
+/// concurrent `pos_read` calls on the same handle are therefore only safe to interleave on
+/// Unix; on Windows they must still be externally synchronized.
+pub trait PosRead {
+    /// Reads bytes starting at `offset` into `buf`, returning the number of bytes read.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying positioned read fails.
+    fn pos_read(&self, buf: &mut [u8], offset: u64) -> Result<usize>;
+}
+
+impl<T: Borrow<File>> PosRead for T {
+    fn pos_read(&self, buf: &mut [u8], offset: u64) -> Result<usize> {
+        #[cfg(unix)]
+        { self.borrow().read_at(buf, offset) }
+        #[cfg(windows)]
+        { self.borrow().seek_read(buf, offset) }
+    }
+}
+
+/// A [`Takes`]-like window backed by [`PosRead`] instead of `Read + Seek`.
+///
+/// Because reads are issued at an explicit offset rather than through a shared cursor, several
+/// `PosTakes` can be constructed over the same `inner` (e.g. an `Arc<File>`) and read from or
+/// seeked independently, with `Seek` never touching the underlying descriptor. On Unix this is
+/// also safe to do concurrently; see the platform note on [`PosRead`] for why Windows still
+/// requires external synchronization between concurrent reads on the same handle.
+pub struct PosTakes<T> {
+    inner: T,
+    start: u64,
+    limit: u64,
+    current: u64, // number of bytes of current pointer from start
+}
+
+impl<T> PosTakes<T> {
+    /// Creates a new positioned-read window over `inner` covering `start..(start + limit)`.
+    pub fn new(inner: T, start: u64, limit: u64) -> Self {
+        Self { inner, start, limit, current: 0 }
+    }
+
+    /// Returns the current logical position within the window, i.e. the offset of the current
+    /// pointer from `start`.
+    pub fn position(&self) -> u64 {
+        self.current
+    }
+
+    /// Returns the total size of the window, i.e. the `limit` passed to [`PosTakes::new`].
+    pub fn size(&self) -> u64 {
+        self.limit
+    }
+}
+
+impl<T: PosRead> Read for PosTakes<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let rem = self.limit - self.current;
+        // Don't call into inner reader at all at EOF because it may still block
+        if rem == 0 {
+            return Ok(0);
+        }
+
+        let max = cmp::min(buf.len() as u64, rem) as usize;
+        let n = self.inner.pos_read(&mut buf[..max], self.start + self.current)?;
+        self.current += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T> Seek for PosTakes<T> {
+    fn seek(&mut self, seek: SeekFrom) -> Result<u64> {
+        let end = self.start + self.limit;
+
+        let target = match seek {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(delta) => (self.start + self.current) as i64 + delta,
+            SeekFrom::End(delta) => end as i64 + delta,
+        };
+
+        if target < self.start as i64 || (target as u64) > end {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond PosTakes range"));
+        }
+
+        // No syscall: the cursor is private to this PosTakes, so seeking is pure arithmetic.
+        let target = target as u64;
+        self.current = target - self.start;
+        Ok(target)
+    }
+}
+
+#[cfg(test)]
+mod pos_takes_tests {
+    use std::io::{Read, Seek, SeekFrom, Write};
+    use std::sync::Arc;
+    use crate::PosTakes;
+
+    fn temp_file_with(contents: &[u8]) -> std::fs::File {
+        let path = std::env::temp_dir().join(format!("takes-test-{:?}", std::thread::current().id()));
+        let mut file = std::fs::OpenOptions::new().create(true).truncate(true).read(true).write(true).open(&path).unwrap();
+        file.write_all(contents).unwrap();
+        file
+    }
+
+    #[test]
+    fn independent_windows_share_one_file_without_contending() {
+        let file = Arc::new(temp_file_with(b"0123456789"));
+
+        let mut first = PosTakes::new(file.clone(), 0, 5);
+        let mut second = PosTakes::new(file.clone(), 5, 5);
+
+        // Interleave reads; each window must stay at its own offset regardless of the other.
+        let mut a = [0u8; 2];
+        let mut b = [0u8; 2];
+        first.read_exact(&mut a).unwrap();
+        second.read_exact(&mut b).unwrap();
+        assert_eq!(&a, b"01");
+        assert_eq!(&b, b"56");
+
+        first.read_exact(&mut a).unwrap();
+        assert_eq!(&a, b"23");
+    }
+
+    #[test]
+    fn seek_is_pure_arithmetic() {
+        let file = Arc::new(temp_file_with(b"0123456789"));
+        let mut window = PosTakes::new(file, 2, 5);
+
+        assert_eq!(window.seek(SeekFrom::End(-1)).unwrap(), 6);
+        let mut buf = [0u8; 1];
+        window.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"6");
+    }
+}
+
+/// A [`Takes`]-like window that also forwards every freshly read byte to a `Write`, created by
+/// [`Ext::tee`].
+///
+/// `max` tracks the highest offset reached so far; only bytes at offsets `>= max` are forwarded
+/// to `writer`, so seeking backward and re-reading does not re-emit bytes already written.
+pub struct Tee<R, W> {
+    inner: R,
+    writer: W,
+    start: u64,
+    limit: u64,
+    current: u64, // number of bytes of current pointer from start
+    max: u64, // highest value current has ever reached
+}
+
+impl<R: Read, W: Write> Read for Tee<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let rem = self.limit - self.current;
+        // Don't call into inner reader at all at EOF because it may still block
+        if rem == 0 {
+            return Ok(0);
+        }
+
+        let max = cmp::min(buf.len() as u64, rem) as usize;
+        let n = self.inner.read(&mut buf[..max])?;
+        let next = self.current + n as u64;
+
+        if next > self.max {
+            let fresh_from = cmp::max(self.current, self.max) - self.current;
+            self.writer.write_all(&buf[fresh_from as usize..n])?;
+            self.max = next;
+        }
+
+        self.current = next;
+        Ok(n)
+    }
+}
+
+impl<R: Read + Seek, W: Write> Seek for Tee<R, W> {
+    fn seek(&mut self, seek: SeekFrom) -> Result<u64> {
+        let end = self.start + self.limit;
+
+        let target = match seek {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(delta) => (self.start + self.current) as i64 + delta,
+            SeekFrom::End(delta) => end as i64 + delta,
+        };
+
+        if target < self.start as i64 || (target as u64) > end {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond Tee range"));
+        }
+
+        let target = (target as u64) - self.start;
+
+        if target > self.current {
+            // Forward skip: stream the jumped-over bytes through `read`, so the gap is still
+            // written to `writer` (Read already skips re-writing anything below `max`).
+            let mut gap = target - self.current;
+            let mut buf = [0u8; 4096];
+            while gap > 0 {
+                let chunk = cmp::min(gap, buf.len() as u64) as usize;
+                let n = self.read(&mut buf[..chunk])?;
+                if n == 0 {
+                    break;
                 }
-                self.inner.seek(SeekFrom::Current(delta))?
-            },
-            SeekFrom::End(_) => unimplemented!("SeekFrom::End implementation would be ambiguous"),
-        })
+                gap -= n as u64;
+            }
+        } else {
+            self.inner.seek(SeekFrom::Start(self.start + target))?;
+            self.current = target;
+        }
+
+        Ok(self.start + self.current)
+    }
+}
+
+impl<R, W> Tee<R, W> {
+    /// Returns the current logical position within the window, i.e. the offset of the current
+    /// pointer from `start`.
+    pub fn position(&self) -> u64 {
+        self.current
+    }
+
+    /// Returns the total size of the window, i.e. the `limit` passed to [`Ext::tee`].
+    pub fn size(&self) -> u64 {
+        self.limit
+    }
+
+    /// Unwraps this `Tee`, returning the inner reader and writer.
+    pub fn into_inner(self) -> (R, W) {
+        (self.inner, self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tee_tests {
+    use std::io::{Cursor, Read, Seek, SeekFrom};
+    use crate::Ext;
+
+    #[test]
+    fn straight_read_writes_every_byte_once() {
+        let mut buf = [0u8; 10];
+        let (_, written) = {
+            let mut tee = Cursor::new(b"0123456789".to_vec()).tee(10, Vec::new()).unwrap();
+            tee.read_exact(&mut buf).unwrap();
+            tee.into_inner()
+        };
+        assert_eq!(&buf, b"0123456789");
+        assert_eq!(written, b"0123456789");
+    }
+
+    #[test]
+    fn backward_seek_and_reread_does_not_duplicate_writes() {
+        let mut tee = Cursor::new(b"0123456789".to_vec()).tee(10, Vec::new()).unwrap();
+        let mut buf = [0u8; 5];
+        tee.read_exact(&mut buf).unwrap();
+
+        tee.seek(SeekFrom::Start(0)).unwrap();
+        tee.read_exact(&mut buf).unwrap();
+
+        let (_, written) = tee.into_inner();
+        assert_eq!(written, b"01234");
+    }
+
+    #[test]
+    fn forward_seek_streams_the_skipped_gap() {
+        let mut tee = Cursor::new(b"0123456789".to_vec()).tee(10, Vec::new()).unwrap();
+        tee.seek(SeekFrom::Start(5)).unwrap();
+
+        let mut buf = [0u8; 2];
+        tee.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"56");
+
+        let (_, written) = tee.into_inner();
+        assert_eq!(written, b"0123456");
+    }
+}
+
+/// Stitches an ordered list of [`Takes`] into a single logical stream.
+///
+/// `Read` rolls over from one segment to the next transparently, and `Seek` (including
+/// `SeekFrom::End`) maps a global logical offset to the segment that contains it via a binary
+/// search over a running prefix sum of segment lengths, rather than a linear walk.
+pub struct ChainedTakes<R> {
+    segments: Vec<Takes<R>>,
+    // prefix_len[i] is the sum of the limits of segments[..i]; has segments.len() + 1 entries.
+    prefix_len: Vec<u64>,
+    pos: u64, // current global logical position, from 0 to prefix_len[segments.len()]
+    index: usize, // segment containing `pos`, or segments.len() at EOF
+}
+
+impl<R> ChainedTakes<R> {
+    /// Creates a chained stream out of `segments`, read and seeked in order.
+    pub fn new(segments: Vec<Takes<R>>) -> Self {
+        let mut prefix_len = Vec::with_capacity(segments.len() + 1);
+        prefix_len.push(0);
+        for segment in &segments {
+            prefix_len.push(prefix_len.last().unwrap() + segment.limit);
+        }
+
+        Self { segments, prefix_len, pos: 0, index: 0 }
+    }
+
+    /// Returns the current logical position within the chained stream.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Returns the total length of the chained stream, i.e. the sum of all segment limits.
+    pub fn size(&self) -> u64 {
+        *self.prefix_len.last().unwrap()
+    }
+}
+
+impl<R: Read> Read for ChainedTakes<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        // An empty buffer reads as 0 from an exhausted segment and a non-exhausted one alike,
+        // so it must not be treated as "this segment is done" or a `read(&mut [])` would
+        // permanently advance past segments that still have data left.
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.index < self.segments.len() {
+            let n = self.segments[self.index].read(buf)?;
+            if n > 0 {
+                self.pos += n as u64;
+                return Ok(n);
+            }
+            self.index += 1;
+        }
+        Ok(0)
+    }
+}
+
+impl<R: Seek> Seek for ChainedTakes<R> {
+    fn seek(&mut self, seek: SeekFrom) -> Result<u64> {
+        let total = self.size();
+
+        let target = match seek {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(delta) => self.pos as i64 + delta,
+            SeekFrom::End(delta) => total as i64 + delta,
+        };
+
+        if target < 0 || (target as u64) > total {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "cannot seek beyond ChainedTakes range"));
+        }
+        let target = target as u64;
+
+        // Binary search the prefix sums for the segment that contains `target`.
+        let index = self.prefix_len.partition_point(|&len| len <= target) - 1;
+
+        // `Read` assumes every segment other than the current one sits at its own start, so a
+        // seek must restore that invariant for *all* segments, not just the one `target` lands
+        // in — otherwise a segment visited before this seek would resume mid-way instead of
+        // from its start the next time `Read` rolls into it.
+        for (i, segment) in self.segments.iter_mut().enumerate() {
+            if i == index {
+                let intra = target - self.prefix_len[i];
+                segment.seek(SeekFrom::Start(segment.start + intra))?;
+            } else {
+                segment.seek(SeekFrom::Start(segment.start))?;
+            }
+        }
+
+        self.index = index;
+        self.pos = target;
+        Ok(target)
+    }
+}
+
+#[cfg(test)]
+mod chained_takes_tests {
+    use std::io::{Cursor, Read, Seek, SeekFrom};
+    use crate::{ChainedTakes, Ext};
+
+    fn two_segments() -> ChainedTakes<Cursor<Vec<u8>>> {
+        ChainedTakes::new(vec![
+            Cursor::new(b"AAAAA".to_vec()).takes(5).unwrap(),
+            Cursor::new(b"BBBBB".to_vec()).takes(5).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn reads_roll_over_segment_boundaries() {
+        let mut chain = two_segments();
+        let mut buf = String::new();
+        chain.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "AAAAABBBBB");
+    }
+
+    #[test]
+    fn rereading_after_seeking_to_start_sees_every_segment_again() {
+        let mut chain = two_segments();
+        let mut buf = String::new();
+        chain.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "AAAAABBBBB");
+
+        chain.seek(SeekFrom::Start(0)).unwrap();
+        buf.clear();
+        chain.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "AAAAABBBBB");
+    }
+
+    #[test]
+    fn seek_end_and_read_crosses_back_into_the_first_segment() {
+        let mut chain = two_segments();
+        chain.seek(SeekFrom::End(-7)).unwrap();
+        let mut buf = String::new();
+        chain.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "AABBBBB");
+    }
+
+    #[test]
+    fn empty_buffer_read_does_not_advance_past_a_segment() {
+        let mut chain = two_segments();
+        assert_eq!(chain.read(&mut []).unwrap(), 0);
+
+        let mut buf = String::new();
+        chain.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "AAAAABBBBB");
     }
 }